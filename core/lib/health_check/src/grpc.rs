@@ -0,0 +1,211 @@
+//! Implementation of the standard [`grpc.health.v1.Health`] protocol on top of
+//! [`AppHealthCheck`] / [`ReactiveHealthCheck`], so that the node can be probed with the same
+//! tooling used for any other gRPC service (Kubernetes / Envoy liveness & readiness probes,
+//! `grpcurl`, etc.).
+//!
+//! [`grpc.health.v1.Health`]: https://github.com/grpc/grpc/blob/master/doc/health-checking.md
+
+use std::{collections::HashMap, pin::Pin, sync::Arc};
+
+use futures::{future, Stream, StreamExt};
+use tonic::{Request, Response, Status};
+use tonic_health::pb::{
+    health_check_response::ServingStatus, health_server::Health as HealthServiceTrait,
+    HealthCheckRequest, HealthCheckResponse,
+};
+
+use crate::{AppHealthCheck, CheckHealth, HealthStatus, ReactiveHealthCheck};
+
+fn serving_status(status: HealthStatus) -> ServingStatus {
+    match status {
+        HealthStatus::Ready | HealthStatus::Affected => ServingStatus::Serving,
+        HealthStatus::NotReady
+        | HealthStatus::ShuttingDown
+        | HealthStatus::ShutDown
+        | HealthStatus::Panicked => ServingStatus::NotServing,
+    }
+}
+
+/// Implementation of the `grpc.health.v1.Health` service backed by an [`AppHealthCheck`].
+///
+/// `Check { service: "" }` (and `Watch` with an empty service name) reports the aggregated
+/// application health. Any other service name is looked up among the components passed to
+/// [`Self::new()`]; an unrecognized name is reported as `SERVICE_UNKNOWN`: `Check` surfaces it
+/// per the protocol as a gRPC `NOT_FOUND` status, while `Watch` sends a single `SERVICE_UNKNOWN`
+/// response and ends the stream.
+#[derive(Debug, Clone)]
+pub struct HealthCheckService {
+    app_health_check: Arc<AppHealthCheck>,
+    components: HashMap<&'static str, ReactiveHealthCheck>,
+}
+
+impl HealthCheckService {
+    /// Creates a new service reporting the aggregated health from `app_health_check`, as well
+    /// as the health of each of `components`, addressable individually by its name.
+    pub fn new(
+        app_health_check: Arc<AppHealthCheck>,
+        components: impl IntoIterator<Item = ReactiveHealthCheck>,
+    ) -> Self {
+        Self {
+            app_health_check,
+            components: components.into_iter().map(|check| (check.name(), check)).collect(),
+        }
+    }
+
+    fn component(&self, service_name: &str) -> Result<&ReactiveHealthCheck, Status> {
+        self.components
+            .get(service_name)
+            .ok_or_else(|| Status::not_found(format!("unknown service `{service_name}`")))
+    }
+}
+
+#[tonic::async_trait]
+impl HealthServiceTrait for HealthCheckService {
+    async fn check(
+        &self,
+        request: Request<HealthCheckRequest>,
+    ) -> Result<Response<HealthCheckResponse>, Status> {
+        let service_name = request.into_inner().service;
+        let status = if service_name.is_empty() {
+            self.app_health_check.check_health().await.status()
+        } else {
+            self.component(&service_name)?.check_health().await.status()
+        };
+        Ok(Response::new(HealthCheckResponse {
+            status: serving_status(status).into(),
+        }))
+    }
+
+    type WatchStream =
+        Pin<Box<dyn Stream<Item = Result<HealthCheckResponse, Status>> + Send + 'static>>;
+
+    async fn watch(
+        &self,
+        request: Request<HealthCheckRequest>,
+    ) -> Result<Response<Self::WatchStream>, Status> {
+        let service_name = request.into_inner().service;
+        if service_name.is_empty() {
+            // Mirror `check()`: an empty service name watches the aggregated application health,
+            // which is what Kubernetes/Envoy probe by default.
+            let snapshots = self.app_health_check.subscribe();
+            let stream = snapshots.map(|snapshot| {
+                Ok(HealthCheckResponse {
+                    status: serving_status(snapshot.status()).into(),
+                })
+            });
+            return Ok(Response::new(Box::pin(stream)));
+        }
+
+        let Ok(component) = self.component(&service_name) else {
+            let response = HealthCheckResponse {
+                status: ServingStatus::ServiceUnknown.into(),
+            };
+            let stream = futures::stream::once(future::ready(Ok::<_, Status>(response)));
+            return Ok(Response::new(Box::pin(stream)));
+        };
+        let mut receiver = component.subscribe_to_changes();
+        let stream = async_stream::stream! {
+            loop {
+                let status = serving_status(receiver.borrow_and_update().status());
+                yield Ok(HealthCheckResponse { status: status.into() });
+                if receiver.changed().await.is_err() {
+                    return;
+                }
+            }
+        };
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::ReactiveHealthCheck;
+
+    #[tokio::test]
+    async fn checking_aggregated_health() {
+        let app_health_check = Arc::new(AppHealthCheck::default());
+        let (component, updater) = ReactiveHealthCheck::new("component");
+        app_health_check.insert_component(component.clone());
+        let service = HealthCheckService::new(app_health_check, [component]);
+
+        let response = service
+            .check(Request::new(HealthCheckRequest {
+                service: String::new(),
+            }))
+            .await
+            .unwrap();
+        assert_eq!(response.into_inner().status, ServingStatus::NotServing as i32);
+
+        updater.update(HealthStatus::Ready.into());
+        let response = service
+            .check(Request::new(HealthCheckRequest {
+                service: "component".to_owned(),
+            }))
+            .await
+            .unwrap();
+        assert_eq!(response.into_inner().status, ServingStatus::Serving as i32);
+    }
+
+    #[tokio::test]
+    async fn checking_unknown_service() {
+        let app_health_check = Arc::new(AppHealthCheck::default());
+        let service = HealthCheckService::new(app_health_check, []);
+
+        let err = service
+            .check(Request::new(HealthCheckRequest {
+                service: "unknown".to_owned(),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+    }
+
+    #[tokio::test]
+    async fn watching_health_changes() {
+        let app_health_check = Arc::new(AppHealthCheck::default());
+        let (component, updater) = ReactiveHealthCheck::new("component");
+        app_health_check.insert_component(component.clone());
+        let service = HealthCheckService::new(app_health_check, [component]);
+
+        let response = service
+            .watch(Request::new(HealthCheckRequest {
+                service: "component".to_owned(),
+            }))
+            .await
+            .unwrap();
+        let mut stream = response.into_inner();
+
+        let first = stream.next().await.unwrap().unwrap();
+        assert_eq!(first.status, ServingStatus::NotServing as i32);
+
+        updater.update(HealthStatus::Ready.into());
+        let second = stream.next().await.unwrap().unwrap();
+        assert_eq!(second.status, ServingStatus::Serving as i32);
+    }
+
+    #[tokio::test]
+    async fn watching_aggregated_health() {
+        let app_health_check = Arc::new(AppHealthCheck::default());
+        let (component, updater) = ReactiveHealthCheck::new("component");
+        app_health_check.insert_component(component.clone());
+        let service = HealthCheckService::new(app_health_check, [component]);
+
+        let response = service
+            .watch(Request::new(HealthCheckRequest {
+                service: String::new(),
+            }))
+            .await
+            .unwrap();
+        let mut stream = response.into_inner();
+
+        let first = stream.next().await.unwrap().unwrap();
+        assert_eq!(first.status, ServingStatus::NotServing as i32);
+
+        updater.update(HealthStatus::Ready.into());
+        let second = stream.next().await.unwrap().unwrap();
+        assert_eq!(second.status, ServingStatus::Serving as i32);
+    }
+}
@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, VecDeque},
     fmt,
     sync::{Arc, Mutex},
     thread,
@@ -8,9 +8,14 @@ use std::{
 
 // Public re-export for other crates to be able to implement the interface.
 pub use async_trait::async_trait;
-use futures::future;
+use arc_swap::ArcSwap;
+use chrono::{DateTime, Utc};
+use futures::{future, Stream};
 use serde::Serialize;
-use tokio::sync::watch;
+use thiserror::Error;
+use tokio::sync::{broadcast, watch};
+
+pub mod grpc;
 
 /// Health status returned as a part of `Health`.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize)]
@@ -83,10 +88,64 @@ impl From<HealthStatus> for Health {
     }
 }
 
+/// Maximum number of [`HealthTransition`]s retained per component; older transitions are evicted
+/// first.
+const MAX_TRANSITIONS_PER_COMPONENT: usize = 100;
+
+/// Capacity of the broadcast channel used to notify [`AppHealthCheck::subscribe()`] subscribers
+/// that a component's health has changed.
+const CHANGE_NOTIFICATION_CAPACITY: usize = 64;
+
+/// Window within which further change notifications are coalesced into a single snapshot by
+/// [`AppHealthCheck::subscribe()`].
+const DEBOUNCE_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Snapshot of the aggregated application health yielded by [`AppHealthCheck::subscribe()`].
+pub type HealthSnapshot = AppHealth;
+
+/// A single observed change of a component's health status, recorded by [`AppHealthCheck`] for
+/// post-mortem debugging of flapping components.
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthTransition {
+    pub timestamp: DateTime<Utc>,
+    pub component_name: &'static str,
+    pub from: HealthStatus,
+    pub to: HealthStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
+}
+
+/// Sink that [`AppHealthCheck`] forwards each recorded [`HealthTransition`] to, e.g. so that a
+/// downstream crate can historize transitions into Postgres for long-term observability
+/// dashboards.
+#[async_trait]
+pub trait HealthEventSink: fmt::Debug + Send + Sync + 'static {
+    async fn record(&self, transition: HealthTransition);
+}
+
+type TransitionHistory = HashMap<&'static str, VecDeque<HealthTransition>>;
+
+/// Error returned when registering a component would introduce a cycle among component
+/// dependencies (see [`AppHealthCheck::insert_component_with_deps()`]).
+#[derive(Debug, Error)]
+#[error("registering component `{component}` would introduce a dependency cycle: {cycle}")]
+pub struct DependencyCycleError {
+    component: &'static str,
+    cycle: String,
+}
+
 /// Application health check aggregating health from multiple components.
 #[derive(Debug)]
 pub struct AppHealthCheck {
-    components: Mutex<Vec<Arc<dyn CheckHealth>>>,
+    components: ArcSwap<Vec<Arc<dyn CheckHealth>>>,
+    /// Maps a component name to the names of the components it depends on.
+    dependencies: Mutex<HashMap<&'static str, Vec<&'static str>>>,
+    // `ArcSwap` rather than `Mutex`: `check_health` reads this once per component on every probe,
+    // while writes only happen on an actual status change, so reads should never block on them.
+    history: Arc<ArcSwap<TransitionHistory>>,
+    event_sink: Option<Arc<dyn HealthEventSink>>,
+    /// Notifies [`Self::subscribe()`] streams that some component's health has changed.
+    change_notifier: broadcast::Sender<()>,
     slow_time_limit: Duration,
     hard_time_limit: Duration,
 }
@@ -100,41 +159,245 @@ impl Default for AppHealthCheck {
 impl AppHealthCheck {
     pub fn new(slow_time_limit: Duration, hard_time_limit: Duration) -> Self {
         Self {
-            components: Mutex::default(),
+            components: ArcSwap::from_pointee(Vec::new()),
+            dependencies: Mutex::default(),
+            history: Arc::new(ArcSwap::from_pointee(TransitionHistory::new())),
+            event_sink: None,
+            change_notifier: broadcast::channel(CHANGE_NOTIFICATION_CAPACITY).0,
             slow_time_limit,
             hard_time_limit,
         }
     }
 
+    /// Attaches a [`HealthEventSink`] that every recorded health transition will be forwarded to.
+    #[must_use]
+    pub fn with_event_sink(mut self, sink: Arc<dyn HealthEventSink>) -> Self {
+        self.event_sink = Some(sink);
+        self
+    }
+
     /// Inserts health check for a component.
     pub fn insert_component(&self, health_check: ReactiveHealthCheck) {
+        self.insert_component_with_deps(health_check, &[])
+            .expect("inserting a component without dependencies cannot introduce a cycle");
+    }
+
+    /// Inserts health check for a component that depends on the (already or not yet registered)
+    /// components named in `depends_on`. The component's effective status reported by
+    /// [`Self::check_health()`] will be the worse of its own status and the worst effective
+    /// status among its dependencies.
+    ///
+    /// Returns an error without registering the component if doing so would introduce a cycle
+    /// in the dependency graph.
+    pub fn insert_component_with_deps(
+        &self,
+        health_check: ReactiveHealthCheck,
+        depends_on: &[&'static str],
+    ) -> Result<(), DependencyCycleError> {
+        self.register_dependencies(health_check.name(), depends_on)?;
+        self.track_history(&health_check);
         self.insert_custom_component(Arc::new(health_check));
+        Ok(())
+    }
+
+    fn register_dependencies(
+        &self,
+        component: &'static str,
+        depends_on: &[&'static str],
+    ) -> Result<(), DependencyCycleError> {
+        if depends_on.is_empty() {
+            return Ok(());
+        }
+
+        let mut guard = self
+            .dependencies
+            .lock()
+            .expect("`AppHealthCheck` dependency graph is poisoned");
+        guard.insert(component, depends_on.to_vec());
+        if let Some(cycle) = Self::find_cycle(&guard, component) {
+            guard.remove(component);
+            return Err(DependencyCycleError {
+                component,
+                cycle: cycle.join(" -> "),
+            });
+        }
+        Ok(())
+    }
+
+    /// Looks for a cycle reachable from `start` in the dependency graph, returning the cycle
+    /// path (starting and ending with `start`) if one exists.
+    fn find_cycle(
+        graph: &HashMap<&'static str, Vec<&'static str>>,
+        start: &'static str,
+    ) -> Option<Vec<&'static str>> {
+        fn visit(
+            graph: &HashMap<&'static str, Vec<&'static str>>,
+            start: &'static str,
+            node: &'static str,
+            path: &mut Vec<&'static str>,
+        ) -> bool {
+            path.push(node);
+            for &dependency in graph.get(node).into_iter().flatten() {
+                if dependency == start {
+                    path.push(dependency);
+                    return true;
+                }
+                if visit(graph, start, dependency, path) {
+                    return true;
+                }
+            }
+            path.pop();
+            false
+        }
+
+        let mut path = Vec::new();
+        visit(graph, start, start, &mut path).then_some(path)
+    }
+
+    /// Spawns a task recording a [`HealthTransition`] each time `health_check`'s health changes
+    /// (its status or its details; a details-only change is recorded with `from == to`),
+    /// forwarding it to the configured [`HealthEventSink`] (if any).
+    fn track_history(&self, health_check: &ReactiveHealthCheck) {
+        let component_name = health_check.name();
+        let mut receiver = health_check.subscribe_to_changes();
+        let history = self.history.clone();
+        let event_sink = self.event_sink.clone();
+        let change_notifier = self.change_notifier.clone();
+        let mut previous = receiver.borrow().clone();
+
+        tokio::spawn(async move {
+            while receiver.changed().await.is_ok() {
+                let current = receiver.borrow_and_update().clone();
+                if current == previous {
+                    continue;
+                }
+                let transition = HealthTransition {
+                    timestamp: Utc::now(),
+                    component_name,
+                    from: previous.status(),
+                    to: current.status(),
+                    details: current.details.clone(),
+                };
+                previous = current;
+
+                history.rcu(|snapshot| {
+                    let mut snapshot = TransitionHistory::clone(snapshot);
+                    let component_history = snapshot.entry(component_name).or_default();
+                    if component_history.len() >= MAX_TRANSITIONS_PER_COMPONENT {
+                        component_history.pop_front();
+                    }
+                    component_history.push_back(transition.clone());
+                    snapshot
+                });
+
+                // No-op if there are currently no `subscribe()` streams listening.
+                let _ = change_notifier.send(());
+
+                if let Some(sink) = &event_sink {
+                    sink.record(transition).await;
+                }
+            }
+        });
+    }
+
+    /// Returns a stream yielding a fresh aggregated [`HealthSnapshot`] every time any registered
+    /// component's health changes, starting with the current snapshot. A burst of near-
+    /// simultaneous updates (e.g. several components becoming ready around the same time) is
+    /// coalesced into a single snapshot.
+    ///
+    /// Only changes to components inserted via [`Self::insert_component()`] /
+    /// [`Self::insert_component_with_deps()`] (i.e. backed by a [`ReactiveHealthCheck`]) trigger
+    /// a new snapshot; components inserted as a raw [`CheckHealth`] implementation must be
+    /// polled via [`Self::check_health()`] instead.
+    pub fn subscribe(self: &Arc<Self>) -> impl Stream<Item = HealthSnapshot> + Send + 'static {
+        let mut changes = self.change_notifier.subscribe();
+        let this = Arc::clone(self);
+
+        async_stream::stream! {
+            yield this.check_health().await;
+
+            'outer: loop {
+                match changes.recv().await {
+                    Ok(()) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+                    Err(broadcast::error::RecvError::Closed) => break 'outer,
+                }
+
+                // Drain any further notifications that arrive within the debounce window so a
+                // burst of updates collapses into the single snapshot taken below.
+                loop {
+                    match tokio::time::timeout(DEBOUNCE_INTERVAL, changes.recv()).await {
+                        Ok(Ok(())) | Ok(Err(broadcast::error::RecvError::Lagged(_))) => continue,
+                        Ok(Err(broadcast::error::RecvError::Closed)) => break 'outer,
+                        Err(_elapsed) => break,
+                    }
+                }
+
+                yield this.check_health().await;
+            }
+        }
+    }
+
+    /// Returns the recorded history of status transitions for `component`, oldest first.
+    pub fn history(&self, component: &str) -> Vec<HealthTransition> {
+        self.history
+            .load()
+            .get(component)
+            .map(|transitions| transitions.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns all recorded transitions (across every component) that happened at or after
+    /// `since`, oldest first.
+    pub fn history_since(&self, since: DateTime<Utc>) -> Vec<HealthTransition> {
+        let snapshot = self.history.load();
+        let mut transitions: Vec<_> = snapshot
+            .values()
+            .flat_map(|component_history| component_history.iter().cloned())
+            .filter(|transition| transition.timestamp >= since)
+            .collect();
+        transitions.sort_by_key(|transition| transition.timestamp);
+        transitions
     }
 
     /// Inserts a custom health check for a component.
     pub fn insert_custom_component(&self, health_check: Arc<dyn CheckHealth>) {
+        self.insert_custom_component_with_deps(health_check, &[])
+            .expect("inserting a component without dependencies cannot introduce a cycle");
+    }
+
+    /// Same as [`Self::insert_custom_component()`], but additionally declares that the component
+    /// depends on the components named in `depends_on`; see
+    /// [`Self::insert_component_with_deps()`] for what that means for aggregation.
+    pub fn insert_custom_component_with_deps(
+        &self,
+        health_check: Arc<dyn CheckHealth>,
+        depends_on: &[&'static str],
+    ) -> Result<(), DependencyCycleError> {
         let health_check_name = health_check.name();
-        let mut guard = self
-            .components
-            .lock()
-            .expect("`AppHealthCheck` is poisoned");
-        if guard.iter().any(|check| check.name() == health_check_name) {
+        self.register_dependencies(health_check_name, depends_on)?;
+
+        // Re-checked on every CAS attempt (not just once against a stale `load()`), so a
+        // redefinition is never missed when two calls race to register the same name.
+        let mut is_redefinition = false;
+        self.components.rcu(|components| {
+            is_redefinition = components.iter().any(|check| check.name() == health_check_name);
+            let mut components = Vec::clone(components);
+            components.push(Arc::clone(&health_check));
+            components
+        });
+        if is_redefinition {
             tracing::warn!(
                 "Health check with name `{health_check_name}` is redefined; only the last mention \
                  will be present in `/health` endpoint output"
             );
         }
-        guard.push(health_check);
+        Ok(())
     }
 
     /// Checks the overall application health. This will query all component checks concurrently.
     pub async fn check_health(&self) -> AppHealth {
-        // Clone checks so that we don't hold a lock for them across a wait point.
-        let health_checks = self
-            .components
-            .lock()
-            .expect("`AppHealthCheck` is poisoned")
-            .clone();
+        // Cheap snapshot: readers never block writers (or each other) on this.
+        let health_checks = self.components.load_full();
 
         let check_futures = health_checks.iter().map(|check| {
             Self::check_health_with_time_limit(
@@ -143,11 +406,37 @@ impl AppHealthCheck {
                 self.hard_time_limit,
             )
         });
-        let components: HashMap<_, _> = future::join_all(check_futures).await.into_iter().collect();
+        let raw_health: HashMap<_, _> = future::join_all(check_futures).await.into_iter().collect();
+        let dependencies = self
+            .dependencies
+            .lock()
+            .expect("`AppHealthCheck` dependency graph is poisoned")
+            .clone();
+        let mut cascade_cache = HashMap::new();
+
+        let components: HashMap<_, _> = raw_health
+            .iter()
+            .map(|(&name, health)| {
+                let (effective_status, degraded_by) =
+                    Self::cascading_status(name, &raw_health, &dependencies, &mut cascade_cache);
+                let health = if effective_status == health.status && degraded_by.is_none() {
+                    health.clone()
+                } else {
+                    Health {
+                        status: effective_status,
+                        details: degraded_by
+                            .map(|dependency| annotate_degraded_by(health.details.clone(), dependency))
+                            .or_else(|| health.details.clone()),
+                    }
+                };
+                let history = self.history(name);
+                (name, ComponentHealth { health, history })
+            })
+            .collect();
 
         let aggregated_status = components
             .values()
-            .map(|health| health.status)
+            .map(|component| component.health.status)
             .max_by_key(|status| status.priority_for_aggregation())
             .unwrap_or(HealthStatus::Ready);
         let inner = aggregated_status.into();
@@ -160,6 +449,36 @@ impl AppHealthCheck {
         health
     }
 
+    /// Computes the effective status of `name`: the worse of its own reported status and the
+    /// (recursively computed) worst effective status among the components it depends on. Also
+    /// returns the name of the dependency responsible for dragging the status down, if any.
+    /// Results are memoized in `cache` since a dependency DAG can be shared by multiple nodes.
+    fn cascading_status(
+        name: &'static str,
+        raw_health: &HashMap<&'static str, Health>,
+        dependencies: &HashMap<&'static str, Vec<&'static str>>,
+        cache: &mut HashMap<&'static str, (HealthStatus, Option<&'static str>)>,
+    ) -> (HealthStatus, Option<&'static str>) {
+        if let Some(&cached) = cache.get(name) {
+            return cached;
+        }
+
+        let own_status = raw_health
+            .get(name)
+            .map_or(HealthStatus::Ready, Health::status);
+        let mut worst = (own_status, None);
+        for &dependency in dependencies.get(name).into_iter().flatten() {
+            let (dependency_status, _) =
+                Self::cascading_status(dependency, raw_health, dependencies, cache);
+            if dependency_status.priority_for_aggregation() > worst.0.priority_for_aggregation() {
+                worst = (dependency_status, Some(dependency));
+            }
+        }
+
+        cache.insert(name, worst);
+        worst
+    }
+
     async fn check_health_with_time_limit(
         check: &dyn CheckHealth,
         slow_time_limit: Duration,
@@ -219,18 +538,56 @@ impl AppHealthCheck {
     }
 }
 
+/// Merges a `degraded_by` marker (naming the dependency that dragged a component's status down)
+/// into its existing details, so an operator can trace a failing component back to its root
+/// cause without losing the component's own details.
+fn annotate_degraded_by(
+    details: Option<serde_json::Value>,
+    dependency: &'static str,
+) -> serde_json::Value {
+    let mut map = match details {
+        Some(serde_json::Value::Object(map)) => map,
+        Some(other) => {
+            let mut map = serde_json::Map::new();
+            map.insert("details".to_owned(), other);
+            map
+        }
+        None => serde_json::Map::new(),
+    };
+    map.insert(
+        "degraded_by".to_owned(),
+        serde_json::Value::String(dependency.to_owned()),
+    );
+    serde_json::Value::Object(map)
+}
+
+/// Health of a single component together with its recorded transition history.
+#[derive(Debug, Serialize)]
+pub struct ComponentHealth {
+    #[serde(flatten)]
+    health: Health,
+    /// Recorded status transitions for this component, oldest first. Omitted when empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    history: Vec<HealthTransition>,
+}
+
 /// Health information for an application consisting of multiple components.
 #[derive(Debug, Serialize)]
 pub struct AppHealth {
     #[serde(flatten)]
     inner: Health,
-    components: HashMap<&'static str, Health>,
+    components: HashMap<&'static str, ComponentHealth>,
 }
 
 impl AppHealth {
     pub fn is_healthy(&self) -> bool {
         self.inner.status.is_healthy()
     }
+
+    /// Returns the aggregated status of the application.
+    pub fn status(&self) -> HealthStatus {
+        self.inner.status()
+    }
 }
 
 /// Interface to be used for health checks.
@@ -331,6 +688,15 @@ impl HealthUpdater {
     }
 }
 
+impl ReactiveHealthCheck {
+    /// Returns a receiver that can be polled / awaited for changes to this component's health,
+    /// without going through the [`CheckHealth`] trait. Used to implement push-based consumers
+    /// (e.g. the `grpc.health.v1.Health/Watch` RPC in [`crate::grpc`]).
+    pub(crate) fn subscribe_to_changes(&self) -> watch::Receiver<Health> {
+        self.health_receiver.clone()
+    }
+}
+
 impl Drop for HealthUpdater {
     fn drop(&mut self) {
         if !self.should_track_drop {
@@ -430,7 +796,7 @@ mod tests {
         let (first_check, first_updater) = ReactiveHealthCheck::new("first");
         let (second_check, second_updater) = ReactiveHealthCheck::new("second");
         let checks = AppHealthCheck {
-            components: Mutex::new(vec![Arc::new(first_check), Arc::new(second_check)]),
+            components: ArcSwap::from_pointee(vec![Arc::new(first_check), Arc::new(second_check)]),
             ..AppHealthCheck::default()
         };
 
@@ -438,11 +804,11 @@ mod tests {
         assert!(!app_health.is_healthy());
         assert_matches!(app_health.inner.status(), HealthStatus::NotReady);
         assert_matches!(
-            app_health.components["first"].status,
+            app_health.components["first"].health.status,
             HealthStatus::NotReady
         );
         assert_matches!(
-            app_health.components["second"].status,
+            app_health.components["second"].health.status,
             HealthStatus::NotReady
         );
 
@@ -451,9 +817,9 @@ mod tests {
         let app_health = checks.check_health().await;
         assert!(!app_health.is_healthy());
         assert_matches!(app_health.inner.status(), HealthStatus::NotReady);
-        assert_matches!(app_health.components["first"].status, HealthStatus::Ready);
+        assert_matches!(app_health.components["first"].health.status, HealthStatus::Ready);
         assert_matches!(
-            app_health.components["second"].status,
+            app_health.components["second"].health.status,
             HealthStatus::NotReady
         );
 
@@ -462,9 +828,9 @@ mod tests {
         let app_health = checks.check_health().await;
         assert!(app_health.is_healthy());
         assert_matches!(app_health.inner.status(), HealthStatus::Affected);
-        assert_matches!(app_health.components["first"].status, HealthStatus::Ready);
+        assert_matches!(app_health.components["first"].health.status, HealthStatus::Ready);
         assert_matches!(
-            app_health.components["second"].status,
+            app_health.components["second"].health.status,
             HealthStatus::Affected
         );
 
@@ -474,12 +840,164 @@ mod tests {
         assert!(!app_health.is_healthy());
         assert_matches!(app_health.inner.status(), HealthStatus::ShutDown);
         assert_matches!(
-            app_health.components["first"].status,
+            app_health.components["first"].health.status,
             HealthStatus::ShutDown
         );
         assert_matches!(
-            app_health.components["second"].status,
+            app_health.components["second"].health.status,
             HealthStatus::Affected
         );
     }
+
+    #[tokio::test]
+    async fn cascading_health_from_dependency() {
+        let checks = AppHealthCheck::default();
+        let (db_check, db_updater) = ReactiveHealthCheck::new("db");
+        let (api_check, api_updater) = ReactiveHealthCheck::new("api");
+        checks.insert_component(db_check);
+        checks
+            .insert_component_with_deps(api_check, &["db"])
+            .unwrap();
+
+        db_updater.update(HealthStatus::Ready.into());
+        api_updater.update(HealthStatus::Ready.into());
+        let app_health = checks.check_health().await;
+        assert_matches!(app_health.components["db"].health.status, HealthStatus::Ready);
+        assert_matches!(app_health.components["api"].health.status, HealthStatus::Ready);
+
+        db_updater.update(HealthStatus::NotReady.into());
+        let app_health = checks.check_health().await;
+        assert_matches!(
+            app_health.components["db"].health.status,
+            HealthStatus::NotReady
+        );
+        assert_matches!(
+            app_health.components["api"].health.status,
+            HealthStatus::NotReady
+        );
+        let degraded_by = app_health.components["api"].health.details.as_ref().unwrap();
+        assert_eq!(degraded_by["degraded_by"], "db");
+    }
+
+    #[tokio::test]
+    async fn rejecting_dependency_cycle() {
+        let checks = AppHealthCheck::default();
+        let (first_check, _first_updater) = ReactiveHealthCheck::new("first");
+        let (second_check, _second_updater) = ReactiveHealthCheck::new("second");
+        checks
+            .insert_component_with_deps(first_check, &["second"])
+            .unwrap();
+
+        let err = checks
+            .insert_component_with_deps(second_check, &["first"])
+            .unwrap_err();
+        assert_eq!(err.component, "second");
+    }
+
+    #[tokio::test]
+    async fn subscribing_to_health_changes() {
+        use futures::StreamExt;
+
+        let checks = Arc::new(AppHealthCheck::default());
+        let (check, updater) = ReactiveHealthCheck::new("test");
+        checks.insert_component(check);
+        let mut snapshots = Box::pin(checks.subscribe());
+
+        let first = snapshots.next().await.unwrap();
+        assert_matches!(first.status(), HealthStatus::NotReady);
+
+        updater.update(HealthStatus::Ready.into());
+        let second = snapshots.next().await.unwrap();
+        assert_matches!(second.status(), HealthStatus::Ready);
+    }
+
+    /// Yields to the executor until `condition` holds, to let the background task spawned by
+    /// [`AppHealthCheck::track_history()`] catch up with a watch update.
+    async fn wait_until(mut condition: impl FnMut() -> bool) {
+        for _ in 0..1_000 {
+            if condition() {
+                return;
+            }
+            tokio::task::yield_now().await;
+        }
+        panic!("condition was not met in time");
+    }
+
+    #[tokio::test]
+    async fn recording_health_history() {
+        let checks = AppHealthCheck::default();
+        let (check, updater) = ReactiveHealthCheck::new("test");
+        checks.insert_component(check);
+
+        updater.update(HealthStatus::Ready.into());
+        wait_until(|| !checks.history("test").is_empty()).await;
+
+        let history = checks.history("test");
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].component_name, "test");
+        assert_matches!(history[0].from, HealthStatus::NotReady);
+        assert_matches!(history[0].to, HealthStatus::Ready);
+
+        updater.update(HealthStatus::Affected.into());
+        wait_until(|| checks.history("test").len() == 2).await;
+
+        let history = checks.history("test");
+        let second_transition_timestamp = history[1].timestamp;
+        assert_matches!(history[1].to, HealthStatus::Affected);
+
+        let recent = checks.history_since(second_transition_timestamp);
+        assert_eq!(recent.len(), 1);
+        assert_matches!(recent[0].to, HealthStatus::Affected);
+    }
+
+    #[tokio::test]
+    async fn history_is_bounded() {
+        let checks = AppHealthCheck::default();
+        let (check, updater) = ReactiveHealthCheck::new("test");
+        checks.insert_component(check);
+
+        for i in 0..MAX_TRANSITIONS_PER_COMPONENT + 5 {
+            let status = if i % 2 == 0 {
+                HealthStatus::Ready
+            } else {
+                HealthStatus::Affected
+            };
+            updater.update(status.into());
+            let expected_len = (i + 1).min(MAX_TRANSITIONS_PER_COMPONENT);
+            wait_until(|| checks.history("test").len() == expected_len).await;
+        }
+
+        let history = checks.history("test");
+        assert_eq!(history.len(), MAX_TRANSITIONS_PER_COMPONENT);
+        assert_matches!(history.last().unwrap().to, HealthStatus::Ready);
+    }
+
+    #[derive(Debug, Default)]
+    struct MockEventSink {
+        events: Mutex<Vec<HealthTransition>>,
+    }
+
+    #[async_trait]
+    impl HealthEventSink for MockEventSink {
+        async fn record(&self, transition: HealthTransition) {
+            self.events.lock().unwrap().push(transition);
+        }
+    }
+
+    #[tokio::test]
+    async fn forwarding_transitions_to_event_sink() {
+        let sink = Arc::new(MockEventSink::default());
+        let checks = AppHealthCheck::default().with_event_sink(sink.clone());
+        let (check, updater) = ReactiveHealthCheck::new("test");
+        checks.insert_component(check);
+
+        updater.update(HealthStatus::Ready.into());
+        wait_until(|| !sink.events.lock().unwrap().is_empty()).await;
+
+        let events = sink.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].component_name, "test");
+        assert_matches!(events[0].from, HealthStatus::NotReady);
+        assert_matches!(events[0].to, HealthStatus::Ready);
+    }
 }